@@ -0,0 +1,152 @@
+use crate::SearchItem;
+
+/// Cheap, approximate set-membership prefilter over the characters in a
+/// string.
+///
+/// Maps `a`-`z` and `0`-`9` (including upper case and the accented or
+/// fullwidth forms that fold to them) to dedicated bits and funnels everything
+/// else (punctuation, whitespace, other non-ASCII, ...) into a single "other"
+/// bit, so a
+/// candidate's bag can be checked against a query's bag with one cheap
+/// `(candidate & query) == query` test before the real DP scan ever runs. A
+/// second bitmask tracks characters seen more than once, so a repeated
+/// query character (e.g. the second `o` in "foo") still rejects candidates
+/// that only contain that character once.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CharBag {
+    seen: u64,
+    repeated: u64,
+}
+
+impl CharBag {
+    /// Builds a bag from every character in `s`.
+    pub fn new(s: &str) -> Self {
+        let mut bag = Self::default();
+        for ch in s.chars() {
+            bag.insert(ch);
+        }
+        bag
+    }
+
+    fn insert(&mut self, ch: char) {
+        let bit = Self::bit_for(ch);
+        if self.seen & bit != 0 {
+            self.repeated |= bit;
+        }
+        self.seen |= bit;
+    }
+
+    fn bit_for(ch: char) -> u64 {
+        // Folded the same way as `MatcherConfig::chars_match`, so an accented
+        // or fullwidth character buckets with its plain ASCII base instead of
+        // always landing in the catch-all bit below. That keeps the prefilter
+        // from rejecting a normalized match (e.g. query `cafe` against
+        // candidate `café`) before the real matcher gets a chance to run.
+        //
+        // `CharBag` is built before a `MatcherConfig` is in scope, so this
+        // folding always applies, even for a search that asks for
+        // `normalize: false` or case-sensitive matching. That only makes the
+        // prefilter over-permissive (a candidate it lets through can still be
+        // rejected by the real matcher), never under-permissive, so it can't
+        // produce a wrong result — just a very occasional wasted DP scan.
+        let lower = ch.to_lowercase().next().unwrap_or(ch);
+        let index = match crate::normalize::normalize(lower).to_ascii_lowercase() {
+            lower @ 'a'..='z' => lower as u32 - 'a' as u32,
+            digit @ '0'..='9' => 26 + (digit as u32 - '0' as u32),
+            _ => 36,
+        };
+        1 << index
+    }
+
+    /// Whether `self` contains every character (and repeat) that `query`
+    /// does, i.e. whether `self` could possibly be a fuzzy match for
+    /// `query`. A `false` result means the candidate can be rejected
+    /// without running the real matcher; a `true` result is not a
+    /// guarantee of a match.
+    pub(crate) fn is_superset_of(&self, query: &CharBag) -> bool {
+        self.seen & query.seen == query.seen && self.repeated & query.repeated == query.repeated
+    }
+}
+
+/// A search candidate paired with a precomputed [`CharBag`], so that
+/// searching the same stable candidate list with many different queries
+/// only pays the bagging cost once instead of on every call.
+#[derive(Clone, Debug)]
+pub struct Bagged<T> {
+    item: T,
+    bag: CharBag,
+}
+
+impl<T: SearchItem> Bagged<T> {
+    pub fn new(item: T) -> Self {
+        let bag = CharBag::new(item.as_str());
+        Self { item, bag }
+    }
+}
+
+impl<T: SearchItem> SearchItem for Bagged<T> {
+    fn as_str(&self) -> &str {
+        self.item.as_str()
+    }
+
+    fn char_bag(&self) -> CharBag {
+        self.bag
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_bags_are_supersets() {
+        let a = CharBag::new("candidate");
+        let b = CharBag::new("candidate");
+        assert!(a.is_superset_of(&b));
+    }
+
+    #[test]
+    fn missing_character_is_rejected() {
+        let candidate = CharBag::new("candidate");
+        let query = CharBag::new("cz");
+        assert!(!candidate.is_superset_of(&query));
+    }
+
+    #[test]
+    fn missing_repeat_is_rejected() {
+        let candidate = CharBag::new("foo");
+        let single = CharBag::new("fo");
+        let double = CharBag::new("foo");
+        assert!(candidate.is_superset_of(&single));
+        assert!(candidate.is_superset_of(&double));
+        assert!(!CharBag::new("fo").is_superset_of(&double));
+    }
+
+    #[test]
+    fn case_and_unicode_are_folded_safely() {
+        let candidate = CharBag::new("Candidate");
+        let query = CharBag::new("cand");
+        assert!(candidate.is_superset_of(&query));
+
+        // Accented characters fold to the same bucket as their ASCII base,
+        // so a normalized query matches a candidate that only has the
+        // accented form (or vice versa).
+        let candidate = CharBag::new("café");
+        let query = CharBag::new("cafe");
+        assert!(candidate.is_superset_of(&query));
+
+        // Characters with no known fold still share a single catch-all
+        // bucket, so they never cause a false rejection even though they
+        // aren't distinguished from one another.
+        let candidate = CharBag::new("寿司");
+        let query = CharBag::new("寿");
+        assert!(candidate.is_superset_of(&query));
+    }
+
+    #[test]
+    fn bagged_reuses_precomputed_bag() {
+        let bagged = Bagged::new("candidate".to_string());
+        assert_eq!(bagged.char_bag(), CharBag::new("candidate"));
+        assert_eq!(bagged.as_str(), "candidate");
+    }
+}
@@ -0,0 +1,167 @@
+/// Tunable scoring weights, candidate size limits, and case-sensitivity
+/// behavior for the matcher.
+///
+/// Use [`MatcherConfig::default`] for the library's built-in weights, or
+/// construct one directly (e.g. via struct update syntax) to tune matching
+/// for a specific use case. Pass it to the `_with` variants of the search
+/// functions (e.g. [`crate::search_score_with`]) instead of the defaulted
+/// ones to use it.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MatcherConfig {
+    pub score_gap_leading: f32,
+    pub score_gap_inner: f32,
+    pub score_gap_trailing: f32,
+
+    pub score_match_consecutive: f32,
+    pub score_match_slash: f32,
+    pub score_match_word: f32,
+    pub score_match_capital: f32,
+    pub score_match_dot: f32,
+
+    pub candidate_max_bytes: usize,
+    pub candidate_max_chars: usize,
+
+    /// When `true`, matching ignores case. Ignored when `smart_case` is
+    /// `true`.
+    pub ignore_case: bool,
+    /// When `true`, matching is case-insensitive unless the query contains
+    /// an uppercase character, in which case it becomes case-sensitive.
+    /// Takes precedence over `ignore_case`.
+    pub smart_case: bool,
+
+    /// When `true` (the default), accented and fullwidth characters are
+    /// folded to their plain ASCII base before comparison, so a query of
+    /// `cafe` matches a candidate of `café`. Set to `false` for byte-exact
+    /// matching.
+    pub normalize: bool,
+}
+
+impl Default for MatcherConfig {
+    fn default() -> Self {
+        Self {
+            score_gap_leading: -0.005,
+            score_gap_inner: -0.01,
+            score_gap_trailing: -0.005,
+
+            score_match_consecutive: 1.0,
+            score_match_slash: 0.9,
+            score_match_word: 0.8,
+            score_match_capital: 0.7,
+            score_match_dot: 0.6,
+
+            candidate_max_bytes: 2048,
+            candidate_max_chars: 1024,
+
+            ignore_case: true,
+            smart_case: false,
+            normalize: true,
+        }
+    }
+}
+
+impl MatcherConfig {
+    pub(crate) const SCORE_MIN: f32 = f32::NEG_INFINITY;
+    pub(crate) const SCORE_MAX: f32 = f32::INFINITY;
+
+    /// Whether a query should be matched case-sensitively under this
+    /// config, given the query itself (needed for `smart_case`).
+    pub(crate) fn case_sensitive(&self, query: &str) -> bool {
+        if self.smart_case {
+            query.chars().any(char::is_uppercase)
+        } else {
+            !self.ignore_case
+        }
+    }
+
+    /// Whether `query_char` and `candidate_char` are considered equal,
+    /// honoring `case_sensitive` as computed by [`Self::case_sensitive`] and
+    /// this config's `normalize` setting.
+    pub(crate) fn chars_match(
+        &self,
+        query_char: char,
+        candidate_char: char,
+        case_sensitive: bool,
+    ) -> bool {
+        let (query_char, candidate_char) = if self.normalize {
+            (
+                crate::normalize::normalize(query_char),
+                crate::normalize::normalize(candidate_char),
+            )
+        } else {
+            (query_char, candidate_char)
+        };
+
+        if case_sensitive {
+            query_char == candidate_char
+        } else {
+            query_char.to_lowercase().eq(candidate_char.to_lowercase())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ignore_case_by_default() {
+        let config = MatcherConfig::default();
+        assert!(!config.case_sensitive("Query"));
+    }
+
+    #[test]
+    fn ignore_case_false_is_always_sensitive() {
+        let config = MatcherConfig {
+            ignore_case: false,
+            ..MatcherConfig::default()
+        };
+        assert!(config.case_sensitive("query"));
+        assert!(config.case_sensitive("Query"));
+    }
+
+    #[test]
+    fn smart_case_follows_query() {
+        let config = MatcherConfig {
+            smart_case: true,
+            ..MatcherConfig::default()
+        };
+        assert!(!config.case_sensitive("query"));
+        assert!(config.case_sensitive("Query"));
+    }
+
+    #[test]
+    fn smart_case_overrides_ignore_case() {
+        let config = MatcherConfig {
+            ignore_case: false,
+            smart_case: true,
+            ..MatcherConfig::default()
+        };
+        assert!(!config.case_sensitive("query"));
+    }
+
+    #[test]
+    fn normalize_folds_accents_by_default() {
+        let config = MatcherConfig::default();
+        assert!(config.chars_match('e', 'é', false));
+    }
+
+    #[test]
+    fn normalize_false_requires_exact_bytes() {
+        let config = MatcherConfig {
+            normalize: false,
+            ..MatcherConfig::default()
+        };
+        assert!(!config.chars_match('e', 'é', false));
+        assert!(config.chars_match('e', 'e', false));
+    }
+
+    #[test]
+    fn normalize_keeps_case_sensitivity_for_folded_letters() {
+        let config = MatcherConfig::default();
+        // Folding 'É' to accent-less form must not also fold its case away:
+        // case-sensitive comparison still needs 'e' vs 'E' to differ.
+        assert!(!config.chars_match('e', 'É', true));
+        assert!(config.chars_match('E', 'É', true));
+        assert!(config.chars_match('e', 'É', false));
+    }
+}
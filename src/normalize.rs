@@ -0,0 +1,80 @@
+/// Folds common Latin-1/Latin-Extended accented letters and fullwidth
+/// compatibility characters to their plain ASCII base, so e.g. a query of
+/// `cafe` can match a candidate of `café`.
+///
+/// The input's case is preserved (`'É'` folds to `'E'`, not `'e'`), so
+/// callers that still need case-sensitive comparison after normalizing
+/// (e.g. `ignore_case: false`, `smart_case`) keep that information.
+///
+/// Characters with no known fold are returned unchanged. This only handles
+/// the common Latin diacritics and fullwidth forms, not full Unicode
+/// normalization (NFKD + combining-mark stripping).
+pub(crate) fn normalize(ch: char) -> char {
+    match ch {
+        'a' | 'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => 'a',
+        'A' | 'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' | 'Ā' | 'Ă' | 'Ą' => 'A',
+        'e' | 'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => 'e',
+        'E' | 'È' | 'É' | 'Ê' | 'Ë' | 'Ē' | 'Ĕ' | 'Ė' | 'Ę' | 'Ě' => 'E',
+        'i' | 'ì' | 'í' | 'î' | 'ï' | 'ī' | 'ĭ' | 'į' | 'ı' => 'i',
+        'I' | 'Ì' | 'Í' | 'Î' | 'Ï' | 'Ī' | 'Ĭ' | 'Į' | 'İ' => 'I',
+        'o' | 'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => 'o',
+        'O' | 'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' | 'Ø' | 'Ō' | 'Ŏ' | 'Ő' => 'O',
+        'u' | 'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => 'u',
+        'U' | 'Ù' | 'Ú' | 'Û' | 'Ü' | 'Ū' | 'Ŭ' | 'Ů' | 'Ű' | 'Ų' => 'U',
+        'y' | 'ý' | 'ÿ' => 'y',
+        'Y' | 'Ý' | 'Ÿ' => 'Y',
+        'n' | 'ñ' | 'ń' | 'ņ' | 'ň' => 'n',
+        'N' | 'Ñ' | 'Ń' | 'Ņ' | 'Ň' => 'N',
+        'c' | 'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => 'c',
+        'C' | 'Ç' | 'Ć' | 'Ĉ' | 'Ċ' | 'Č' => 'C',
+        's' | 'ß' | 'ś' | 'ŝ' | 'ş' | 'š' => 's',
+        'S' | 'Ś' | 'Ŝ' | 'Ş' | 'Š' => 'S',
+        'z' | 'ź' | 'ż' | 'ž' => 'z',
+        'Z' | 'Ź' | 'Ż' | 'Ž' => 'Z',
+        // Fullwidth ASCII compatibility forms (e.g. U+FF41 "ａ" -> 'a')
+        '\u{FF21}'..='\u{FF3A}' => (b'A' + (ch as u32 - 0xFF21) as u8) as char,
+        '\u{FF41}'..='\u{FF5A}' => (b'a' + (ch as u32 - 0xFF41) as u8) as char,
+        '\u{FF10}'..='\u{FF19}' => (b'0' + (ch as u32 - 0xFF10) as u8) as char,
+        _ => ch,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn folds_latin1_diacritics() {
+        assert_eq!(normalize('é'), 'e');
+        assert_eq!(normalize('É'), 'E');
+        assert_eq!(normalize('ü'), 'u');
+        assert_eq!(normalize('ñ'), 'n');
+    }
+
+    #[test]
+    fn folds_latin_extended_a() {
+        assert_eq!(normalize('č'), 'c');
+        assert_eq!(normalize('ō'), 'o');
+    }
+
+    #[test]
+    fn folds_fullwidth_forms() {
+        assert_eq!(normalize('ａ'), 'a');
+        assert_eq!(normalize('Ａ'), 'A');
+        assert_eq!(normalize('５'), '5');
+    }
+
+    #[test]
+    fn preserves_case_of_folded_letters() {
+        assert_eq!(normalize('a'), 'a');
+        assert_eq!(normalize('A'), 'A');
+        assert_eq!(normalize('É'), 'E');
+        assert_eq!(normalize('ē'), 'e');
+    }
+
+    #[test]
+    fn leaves_unmapped_characters_alone() {
+        assert_eq!(normalize('あ'), 'あ');
+        assert_eq!(normalize('!'), '!');
+    }
+}
@@ -0,0 +1,243 @@
+use std::collections::BinaryHeap;
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+
+use crate::char_bag::CharBag;
+use crate::score::{has_match, score_inner};
+use crate::{MatcherConfig, ScoreResult, ScoreResults, SearchItem};
+
+/// How many candidates a worker thread scores between checks of `cancel`.
+const CANCEL_CHECK_INTERVAL: usize = 256;
+
+/// Search among a collection of candidates using the given query, like
+/// [`crate::search_score`], but sharding the candidates across worker
+/// threads and keeping only the best `max_results` scores in a bounded
+/// min-heap per shard instead of materializing and sorting a `Vec` the size
+/// of `candidates`. Scores below `min_score` are dropped before they can
+/// consume a heap slot. `cancel` is checked periodically so a caller driving
+/// a live-updating UI can drop a stale search as soon as a newer query comes
+/// in, rather than waiting for it to finish.
+pub fn search_score_parallel<T: SearchItem + Sync>(
+    query: &str,
+    candidates: &[T],
+    max_results: usize,
+    min_score: f32,
+    cancel: &AtomicBool,
+) -> ScoreResults {
+    search_score_parallel_with(
+        query,
+        candidates,
+        max_results,
+        min_score,
+        cancel,
+        &MatcherConfig::default(),
+    )
+}
+
+/// Like [`search_score_parallel`], but using the weights, limits, and case
+/// sensitivity from `config` instead of the defaults
+pub fn search_score_parallel_with<T: SearchItem + Sync>(
+    query: &str,
+    candidates: &[T],
+    max_results: usize,
+    min_score: f32,
+    cancel: &AtomicBool,
+    config: &MatcherConfig,
+) -> ScoreResults {
+    if max_results == 0 || candidates.is_empty() {
+        return Vec::new();
+    }
+
+    let query_bag = CharBag::new(query);
+    let worker_count = std::thread::available_parallelism()
+        .map(std::num::NonZeroUsize::get)
+        .unwrap_or(1)
+        .min(candidates.len());
+    let shard_size = candidates.len().div_ceil(worker_count);
+
+    let shared = ShardContext {
+        query,
+        max_results,
+        min_score,
+        query_bag: &query_bag,
+        cancel,
+        config,
+    };
+
+    let heaps = std::thread::scope(|scope| {
+        candidates
+            .chunks(shard_size)
+            .enumerate()
+            .map(|(shard, chunk)| {
+                let shared = &shared;
+                scope.spawn(move || score_shard(chunk, shard * shard_size, shared))
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|handle| handle.join().unwrap_or_default())
+            .collect::<Vec<_>>()
+    });
+
+    merge_heaps(heaps, max_results)
+}
+
+/// Parameters shared by every shard of a single [`search_score_parallel_with`]
+/// call, grouped so `score_shard` doesn't need a long argument list.
+struct ShardContext<'a> {
+    query: &'a str,
+    max_results: usize,
+    min_score: f32,
+    query_bag: &'a CharBag,
+    cancel: &'a AtomicBool,
+    config: &'a MatcherConfig,
+}
+
+/// Scores one shard of candidates into a bounded min-heap of at most
+/// `max_results` entries, bailing out early if `cancel` becomes set.
+fn score_shard<T: SearchItem>(
+    candidates: &[T],
+    index_offset: usize,
+    shared: &ShardContext,
+) -> BinaryHeap<ScoreResult> {
+    let mut heap = BinaryHeap::with_capacity(shared.max_results);
+
+    for (i, candidate) in candidates.iter().enumerate() {
+        if i % CANCEL_CHECK_INTERVAL == 0 && shared.cancel.load(AtomicOrdering::Relaxed) {
+            break;
+        }
+
+        if !candidate.char_bag().is_superset_of(shared.query_bag)
+            || !has_match(shared.query, candidate, shared.config)
+        {
+            continue;
+        }
+
+        let result = score_inner(
+            shared.query,
+            candidate.as_str(),
+            index_offset + i,
+            shared.config,
+        );
+        if result.score < shared.min_score {
+            continue;
+        }
+        push_bounded(&mut heap, result, shared.max_results);
+    }
+
+    heap
+}
+
+/// Pushes `result` onto `heap`, evicting the current worst entry first if
+/// the heap is already at `max_results` and `result` beats it.
+fn push_bounded(heap: &mut BinaryHeap<ScoreResult>, result: ScoreResult, max_results: usize) {
+    if heap.len() < max_results {
+        heap.push(result);
+    } else if heap.peek().is_some_and(|worst| result < *worst) {
+        heap.pop();
+        heap.push(result);
+    }
+}
+
+fn merge_heaps(heaps: Vec<BinaryHeap<ScoreResult>>, max_results: usize) -> ScoreResults {
+    let mut merged = BinaryHeap::with_capacity(max_results);
+    for result in heaps.into_iter().flatten() {
+        push_bounded(&mut merged, result, max_results);
+    }
+
+    let mut out = merged.into_vec();
+    out.sort_unstable_by(|left, right| {
+        left.partial_cmp(right).unwrap_or(std::cmp::Ordering::Less)
+    });
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidates(names: &[&str]) -> Vec<String> {
+        names.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn matches_sequential_top_results() {
+        let candidates = candidates(&[
+            "app/models/order",
+            "app/models/zrder",
+            "README.md",
+            "app_order_test",
+        ]);
+        let cancel = AtomicBool::new(false);
+
+        let parallel = search_score_parallel("amor", &candidates, 2, MatcherConfig::SCORE_MIN, &cancel);
+        let sequential = crate::search_score("amor", &candidates);
+
+        let actual: Vec<usize> = parallel.iter().map(|r| r.index).collect();
+        let expected: Vec<usize> = sequential.iter().take(2).map(|r| r.index).collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn truncates_to_max_results() {
+        let candidates: Vec<String> = (0..50).map(|i| format!("candidate{i}")).collect();
+        let cancel = AtomicBool::new(false);
+
+        let results = search_score_parallel(
+            "candidate",
+            &candidates,
+            5,
+            MatcherConfig::SCORE_MIN,
+            &cancel,
+        );
+        assert_eq!(results.len(), 5);
+    }
+
+    #[test]
+    fn empty_candidates_and_zero_max_results() {
+        let cancel = AtomicBool::new(false);
+        assert!(
+            search_score_parallel("query", &candidates(&[]), 10, MatcherConfig::SCORE_MIN, &cancel)
+                .is_empty()
+        );
+        assert!(search_score_parallel(
+            "query",
+            &candidates(&["candidate"]),
+            0,
+            MatcherConfig::SCORE_MIN,
+            &cancel
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn drops_results_below_min_score() {
+        let candidates = candidates(&["app/models/order", "app/models/zrder"]);
+        let cancel = AtomicBool::new(false);
+
+        let unfiltered =
+            search_score_parallel("amor", &candidates, 10, MatcherConfig::SCORE_MIN, &cancel);
+        assert_eq!(unfiltered.len(), 2);
+
+        let threshold = unfiltered
+            .iter()
+            .map(|r| r.score)
+            .fold(f32::NEG_INFINITY, f32::max);
+        let filtered = search_score_parallel(
+            "amor",
+            &candidates,
+            10,
+            threshold,
+            &cancel,
+        );
+        assert_eq!(filtered.len(), 1);
+    }
+
+    #[test]
+    fn cancel_flag_stops_before_scoring() {
+        let candidates: Vec<String> = (0..1000).map(|i| format!("candidate{i}")).collect();
+        let cancel = AtomicBool::new(true);
+
+        let results =
+            search_score_parallel("candidate", &candidates, 10, MatcherConfig::SCORE_MIN, &cancel);
+        assert!(results.is_empty());
+    }
+}
@@ -0,0 +1,206 @@
+use std::cmp::Ordering;
+
+use crate::score::{has_match, score_inner};
+use crate::{MatcherConfig, ScoreResult, ScoreResults, SearchItem};
+
+/// Fixed score contributed by an anchored or exact atom, so it counts
+/// towards ordering candidates but doesn't have to compete on the same
+/// scale as the fuzzy DP score.
+const ATOM_MATCH_BONUS: f32 = 1.0;
+
+/// Search among a collection of candidates using an fzf-style query:
+/// whitespace-separated atoms that are all AND-ed together. Each atom may
+/// be:
+///
+/// - `'foo` — exact substring match (not fuzzy)
+/// - `^foo` — candidate must start with `foo`
+/// - `foo$` — candidate must end with `foo`
+/// - `!foo` — candidate must *not* contain `foo`
+/// - `foo`  — the regular fuzzy match
+///
+/// A candidate is kept only if every positive atom matches and no negated
+/// atom matches. Its score is the sum of the fuzzy atoms' scores, plus a
+/// fixed bonus per matching anchored/exact atom.
+pub fn search_pattern<T: SearchItem>(query: &str, candidates: &[T]) -> ScoreResults {
+    search_pattern_with(query, candidates, &MatcherConfig::default())
+}
+
+/// Like [`search_pattern`], but using the weights, limits, and case
+/// sensitivity from `config` instead of the defaults
+pub fn search_pattern_with<T: SearchItem>(
+    query: &str,
+    candidates: &[T],
+    config: &MatcherConfig,
+) -> ScoreResults {
+    let pattern = Pattern::parse(query);
+
+    let mut out = candidates
+        .iter()
+        .enumerate()
+        .filter_map(|(i, c)| {
+            pattern
+                .score(c.as_str(), config)
+                .map(|score| ScoreResult::with_score(i, score))
+        })
+        .collect::<Vec<_>>();
+
+    out.sort_unstable_by(|left, right| left.partial_cmp(right).unwrap_or(Ordering::Less));
+    out
+}
+
+struct Pattern {
+    atoms: Vec<Atom>,
+}
+
+impl Pattern {
+    fn parse(query: &str) -> Self {
+        let atoms = query.split_whitespace().map(Atom::parse).collect();
+        Self { atoms }
+    }
+
+    /// Scores `candidate` against every atom, short-circuiting to `None` as
+    /// soon as a positive atom fails to match or a negated one does.
+    fn score(&self, candidate: &str, config: &MatcherConfig) -> Option<f32> {
+        self.atoms.iter().try_fold(0.0, |total, atom| {
+            atom.score(candidate, config).map(|score| total + score)
+        })
+    }
+}
+
+enum Atom {
+    /// Bare `foo`: the existing fuzzy DP matcher.
+    Fuzzy(String),
+    /// `'foo`: exact substring, matched with [`str::contains`].
+    Exact(String),
+    /// `^foo`: candidate must start with `foo`.
+    Prefix(String),
+    /// `foo$`: candidate must end with `foo`.
+    Suffix(String),
+    /// `!foo`: candidate must not contain `foo`.
+    Negate(String),
+}
+
+impl Atom {
+    fn parse(fragment: &str) -> Self {
+        if let Some(rest) = fragment.strip_prefix('\'') {
+            Self::Exact(rest.to_string())
+        } else if let Some(rest) = fragment.strip_prefix('^') {
+            Self::Prefix(rest.to_string())
+        } else if let Some(rest) = fragment.strip_prefix('!') {
+            Self::Negate(rest.to_string())
+        } else if let Some(rest) = fragment.strip_suffix('$') {
+            Self::Suffix(rest.to_string())
+        } else {
+            Self::Fuzzy(fragment.to_string())
+        }
+    }
+
+    /// `None` means the candidate is rejected outright; `Some` carries this
+    /// atom's contribution to the candidate's total score.
+    fn score(&self, candidate: &str, config: &MatcherConfig) -> Option<f32> {
+        match self {
+            Self::Fuzzy(query) => has_match(query, candidate, config)
+                .then(|| score_inner(query, candidate, 0, config).score),
+            Self::Exact(needle) => contains(candidate, needle, config).then_some(ATOM_MATCH_BONUS),
+            Self::Prefix(needle) => {
+                starts_with(candidate, needle, config).then_some(ATOM_MATCH_BONUS)
+            }
+            Self::Suffix(needle) => {
+                ends_with(candidate, needle, config).then_some(ATOM_MATCH_BONUS)
+            }
+            Self::Negate(needle) => (!contains(candidate, needle, config)).then_some(0.0),
+        }
+    }
+}
+
+fn contains(candidate: &str, needle: &str, config: &MatcherConfig) -> bool {
+    if config.case_sensitive(needle) {
+        candidate.contains(needle)
+    } else {
+        candidate.to_lowercase().contains(&needle.to_lowercase())
+    }
+}
+
+fn starts_with(candidate: &str, needle: &str, config: &MatcherConfig) -> bool {
+    if config.case_sensitive(needle) {
+        candidate.starts_with(needle)
+    } else {
+        candidate.to_lowercase().starts_with(&needle.to_lowercase())
+    }
+}
+
+fn ends_with(candidate: &str, needle: &str, config: &MatcherConfig) -> bool {
+    if config.case_sensitive(needle) {
+        candidate.ends_with(needle)
+    } else {
+        candidate.to_lowercase().ends_with(&needle.to_lowercase())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn search(query: &str, candidates: &[&str]) -> Vec<usize> {
+        let candidates: Vec<String> = candidates.iter().map(|s| s.to_string()).collect();
+        search_pattern(query, &candidates)
+            .into_iter()
+            .map(|r| r.index)
+            .collect()
+    }
+
+    #[test]
+    fn bare_atom_is_fuzzy() {
+        assert_eq!(search("amor", &["app/models/order"]), vec![0]);
+    }
+
+    #[test]
+    fn exact_atom_requires_substring() {
+        assert_eq!(
+            search("'order", &["app/models/order", "app/models/orders"]),
+            vec![0, 1]
+        );
+        assert!(search("'zrder", &["app/models/order"]).is_empty());
+    }
+
+    #[test]
+    fn prefix_atom_anchors_to_start() {
+        assert_eq!(
+            search("^app", &["app/models/order", "src/app/models"]),
+            vec![0]
+        );
+    }
+
+    #[test]
+    fn suffix_atom_anchors_to_end() {
+        assert_eq!(
+            search("order$", &["app/models/order", "app/models/orders"]),
+            vec![0]
+        );
+    }
+
+    #[test]
+    fn negated_atom_excludes_matches() {
+        assert_eq!(
+            search("!test", &["app/models/order", "app/models/order_test"]),
+            vec![0]
+        );
+    }
+
+    #[test]
+    fn atoms_are_combined_with_and() {
+        assert_eq!(
+            search(
+                "^app order$ !test",
+                &["app/models/order", "app/models/order_test", "src/order"]
+            ),
+            vec![0]
+        );
+    }
+
+    #[test]
+    fn fuzzy_atoms_rank_by_score() {
+        let results = search("amor", &["app/models/order", "app/models/zrder"]);
+        assert_eq!(results[0], 0);
+    }
+}
@@ -1,18 +1,18 @@
-use crate::Params;
+use crate::MatcherConfig;
 use std::cmp::Ordering;
 
 type ScoreMatrix = ndarray::Array2<f32>;
 
 /// Result of querying the score against a candidate
 #[derive(Copy, Clone, Debug)]
-pub struct Score {
+pub struct ScoreResult {
     pub index: usize,
     pub score: f32,
 }
 
-impl Score {
+impl ScoreResult {
     pub const fn new(index: usize) -> Self {
-        Self::with_score(index, Params::SCORE_MIN)
+        Self::with_score(index, MatcherConfig::SCORE_MIN)
     }
 
     pub const fn with_score(index: usize, score: f32) -> Self {
@@ -20,7 +20,53 @@ impl Score {
     }
 }
 
-impl PartialOrd for Score {
+impl PartialOrd for ScoreResult {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for ScoreResult {
+    fn eq(&self, other: &Self) -> bool {
+        self.score == other.score
+    }
+}
+
+impl Eq for ScoreResult {}
+
+impl Ord for ScoreResult {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.score
+            .partial_cmp(&other.score)
+            .unwrap_or(Ordering::Less)
+            .reverse()
+    }
+}
+
+/// Result of locating a query within a candidate: the score plus the
+/// character indices of the candidate that the query matched against.
+#[derive(Clone, Debug)]
+pub struct LocateResult {
+    pub index: usize,
+    pub score: f32,
+    pub positions: Vec<usize>,
+}
+
+impl LocateResult {
+    pub const fn new(index: usize) -> Self {
+        Self::with_positions(index, MatcherConfig::SCORE_MIN, Vec::new())
+    }
+
+    pub const fn with_positions(index: usize, score: f32, positions: Vec<usize>) -> Self {
+        Self {
+            index,
+            score,
+            positions,
+        }
+    }
+}
+
+impl PartialOrd for LocateResult {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(
             self.score
@@ -31,79 +77,223 @@ impl PartialOrd for Score {
     }
 }
 
-impl PartialEq for Score {
+impl PartialEq for LocateResult {
     fn eq(&self, other: &Self) -> bool {
         self.score == other.score
     }
 }
 
-impl Eq for Score {}
+impl Eq for LocateResult {}
 
 #[allow(dead_code)]
-pub fn has_match(query: &str, candidate: &(impl crate::SearchItem + ?Sized)) -> bool {
+pub fn has_match(
+    query: &str,
+    candidate: &(impl crate::SearchItem + ?Sized),
+    config: &MatcherConfig,
+) -> bool {
+    let case_sensitive = config.case_sensitive(query);
     let mut chars = candidate.as_str().chars();
-    query
-        .chars()
-        .all(|right| chars.any(|left| left.to_lowercase().eq(right.to_lowercase())))
+    query.chars().all(|right| {
+        chars.any(|left| config.chars_match(left, right, case_sensitive))
+    })
 }
 
-pub fn score(query: &str, candidate: &str, index: usize) -> Score {
-    let (q_len, c_len) = match Metric::classify(query, candidate) {
-        Metric::Score(s) => return Score::with_score(index, s),
+pub(crate) fn score_inner(
+    query: &str,
+    candidate: &str,
+    index: usize,
+    config: &MatcherConfig,
+) -> ScoreResult {
+    let (q_len, c_len) = match Metric::classify(query, candidate, config) {
+        Metric::Score(s) => return ScoreResult::with_score(index, s),
         Metric::Lengths(q, c) => (q, c),
     };
 
-    let (best_score_overall, _) = {
-        let match_bonuses = candidate_match_bonuses(candidate);
+    let score = score_rows(query, candidate, q_len, c_len, config);
 
-        // Matrix of the best score for each position ending in a match
-        let mut best_score_w_ending = ScoreMatrix::zeros((q_len, c_len));
-        // Matrix for the best score for each position.
-        let mut best_score_overall = ScoreMatrix::zeros((q_len, c_len));
+    ScoreResult::with_score(index, score)
+}
 
-        for (i, q_char) in query.chars().enumerate() {
-            let mut prev_score = Params::SCORE_MIN;
-            let gap_score = if i == q_len - 1 {
-                Params::SCORE_GAP_TRAILING
+pub(crate) fn locate_inner(
+    query: &str,
+    candidate: &str,
+    index: usize,
+    config: &MatcherConfig,
+) -> LocateResult {
+    let (q_len, c_len) = match Metric::classify(query, candidate, config) {
+        Metric::Score(s) => {
+            // An exact match means the whole candidate matched; anything
+            // else (too long, empty query) has no positions to report.
+            let positions = if s == MatcherConfig::SCORE_MAX {
+                (0..candidate.chars().count()).collect()
             } else {
-                Params::SCORE_GAP_INNER
+                Vec::new()
             };
+            return LocateResult::with_positions(index, s, positions);
+        }
+        Metric::Lengths(q, c) => (q, c),
+    };
+
+    let (best_score_overall, best_score_w_ending) =
+        compute_matrices(query, candidate, q_len, c_len, config);
+    let score = best_score_overall[[q_len - 1, c_len - 1]];
+    let positions = traceback(&best_score_overall, &best_score_w_ending, q_len, c_len);
+
+    LocateResult::with_positions(index, score, positions)
+}
 
-            for (j, c_char) in candidate.chars().enumerate() {
-                if q_char.to_lowercase().eq(c_char.to_lowercase()) {
-                    // Get the score bonus for matching this char
-                    let score = if i == 0 {
-                        // Beginning of the query, penalty for leading gap
-                        (j as f32).mul_add(Params::SCORE_GAP_LEADING, match_bonuses[j])
-                    } else if j != 0 {
-                        // Middle of both query and candidate
-                        // Either give it the match bonus, or use the consecutive
-                        // match (which wil always be higher, but doesn't stack
-                        // with match bonus)
-                        (best_score_overall[[i - 1, j - 1]] + match_bonuses[j]).max(
-                            best_score_w_ending[[i - 1, j - 1]] + Params::SCORE_MATCH_CONSECUTIVE,
-                        )
-                    } else {
-                        Params::SCORE_MIN
-                    };
-
-                    prev_score = score.max(prev_score + gap_score);
-                    best_score_overall[[i, j]] = prev_score;
-                    best_score_w_ending[[i, j]] = score;
+/// Fills in the `best_score_overall`/`best_score_w_ending` DP matrices for a
+/// query known to be shorter than the candidate (see [`Metric::classify`]).
+fn compute_matrices(
+    query: &str,
+    candidate: &str,
+    q_len: usize,
+    c_len: usize,
+    config: &MatcherConfig,
+) -> (ScoreMatrix, ScoreMatrix) {
+    let case_sensitive = config.case_sensitive(query);
+    let match_bonuses = candidate_match_bonuses(candidate, config);
+
+    // Matrix of the best score for each position ending in a match
+    let mut best_score_w_ending = ScoreMatrix::zeros((q_len, c_len));
+    // Matrix for the best score for each position.
+    let mut best_score_overall = ScoreMatrix::zeros((q_len, c_len));
+
+    for (i, q_char) in query.chars().enumerate() {
+        let mut prev_score = MatcherConfig::SCORE_MIN;
+        let gap_score = if i == q_len - 1 {
+            config.score_gap_trailing
+        } else {
+            config.score_gap_inner
+        };
+
+        for (j, c_char) in candidate.chars().enumerate() {
+            if config.chars_match(q_char, c_char, case_sensitive) {
+                // Get the score bonus for matching this char
+                let score = if i == 0 {
+                    // Beginning of the query, penalty for leading gap
+                    (j as f32).mul_add(config.score_gap_leading, match_bonuses[j])
+                } else if j != 0 {
+                    // Middle of both query and candidate
+                    // Either give it the match bonus, or use the consecutive
+                    // match (which wil always be higher, but doesn't stack
+                    // with match bonus)
+                    (best_score_overall[[i - 1, j - 1]] + match_bonuses[j]).max(
+                        best_score_w_ending[[i - 1, j - 1]] + config.score_match_consecutive,
+                    )
                 } else {
-                    // Give the score penalty for the gap
-                    prev_score += gap_score;
-                    best_score_overall[[i, j]] = prev_score;
-                    // We don't end in a match
-                    best_score_w_ending[[i, j]] = Params::SCORE_MIN;
-                }
+                    MatcherConfig::SCORE_MIN
+                };
+
+                prev_score = score.max(prev_score + gap_score);
+                best_score_overall[[i, j]] = prev_score;
+                best_score_w_ending[[i, j]] = score;
+            } else {
+                // Give the score penalty for the gap
+                prev_score += gap_score;
+                best_score_overall[[i, j]] = prev_score;
+                // We don't end in a match
+                best_score_w_ending[[i, j]] = MatcherConfig::SCORE_MIN;
             }
         }
+    }
 
-        (best_score_overall, best_score_w_ending)
-    };
+    (best_score_overall, best_score_w_ending)
+}
+
+/// Computes the same recurrence as [`compute_matrices`], but only keeps the
+/// current and previous query rows instead of the full `q_len × c_len`
+/// matrices, since row `i` only ever reads row `i - 1`. This cuts the
+/// per-candidate memory from `O(q_len * c_len)` to `O(c_len)`, which matters
+/// on long candidate lists where plain scoring (no traceback) dominates.
+/// [`locate_inner`] still needs the full matrices to walk back through, so it
+/// uses [`compute_matrices`] instead.
+fn score_rows(
+    query: &str,
+    candidate: &str,
+    q_len: usize,
+    c_len: usize,
+    config: &MatcherConfig,
+) -> f32 {
+    let case_sensitive = config.case_sensitive(query);
+    let match_bonuses = candidate_match_bonuses(candidate, config);
+
+    let mut prev_overall = vec![0.0; c_len];
+    let mut prev_w_ending = vec![0.0; c_len];
+    let mut cur_overall = vec![0.0; c_len];
+    let mut cur_w_ending = vec![0.0; c_len];
+
+    for (i, q_char) in query.chars().enumerate() {
+        let mut prev_score = MatcherConfig::SCORE_MIN;
+        let gap_score = if i == q_len - 1 {
+            config.score_gap_trailing
+        } else {
+            config.score_gap_inner
+        };
+
+        for (j, c_char) in candidate.chars().enumerate() {
+            if config.chars_match(q_char, c_char, case_sensitive) {
+                let score = if i == 0 {
+                    (j as f32).mul_add(config.score_gap_leading, match_bonuses[j])
+                } else if j != 0 {
+                    (prev_overall[j - 1] + match_bonuses[j])
+                        .max(prev_w_ending[j - 1] + config.score_match_consecutive)
+                } else {
+                    MatcherConfig::SCORE_MIN
+                };
+
+                prev_score = score.max(prev_score + gap_score);
+                cur_overall[j] = prev_score;
+                cur_w_ending[j] = score;
+            } else {
+                prev_score += gap_score;
+                cur_overall[j] = prev_score;
+                cur_w_ending[j] = MatcherConfig::SCORE_MIN;
+            }
+        }
+
+        std::mem::swap(&mut prev_overall, &mut cur_overall);
+        std::mem::swap(&mut prev_w_ending, &mut cur_w_ending);
+    }
 
-    Score::with_score(index, best_score_overall[[q_len - 1, c_len - 1]])
+    prev_overall[c_len - 1]
+}
+
+/// Walks the DP matrices backward from the last query row/candidate column
+/// and returns, in order, one candidate index per query character. A column
+/// is taken as matched wherever its best score ending in a match equals its
+/// best overall score; otherwise the walk moves left. Whether a match was
+/// reached through a consecutive run is not tracked, so the indices can differ
+/// from the alignment that produced the score: `amor` in `app/models/order`
+/// yields `[0, 4, 5, 12]`, while the score comes from matching `o` at 11.
+fn traceback(
+    best_score_overall: &ScoreMatrix,
+    best_score_w_ending: &ScoreMatrix,
+    q_len: usize,
+    c_len: usize,
+) -> Vec<usize> {
+    let mut positions = Vec::with_capacity(q_len);
+
+    let mut i = q_len - 1;
+    let mut j = c_len - 1;
+    loop {
+        if best_score_w_ending[[i, j]] == best_score_overall[[i, j]] {
+            // This position was reached via a match, move diagonally.
+            positions.push(j);
+            if i == 0 {
+                break;
+            }
+            i -= 1;
+        }
+
+        if j == 0 {
+            break;
+        }
+        j -= 1;
+    }
+
+    positions.reverse();
+    positions
 }
 
 enum Metric {
@@ -112,10 +302,10 @@ enum Metric {
 }
 
 impl Metric {
-    fn classify(query: &str, candidate: &str) -> Self {
-        if candidate.len() > Params::CANDIDATE_MAX_BYTES || query.is_empty() {
+    fn classify(query: &str, candidate: &str, config: &MatcherConfig) -> Self {
+        if candidate.len() > config.candidate_max_bytes || query.is_empty() {
             // Candidate too long or query too short
-            return Self::Score(Params::SCORE_MIN);
+            return Self::Score(MatcherConfig::SCORE_MIN);
         }
 
         let q_len = query.chars().count();
@@ -125,39 +315,39 @@ impl Metric {
             // This is only called when there _is_ a match (candidate contains all
             // chars of query in the right order, so equal lengths mean equal
             // strings
-            return Self::Score(Params::SCORE_MAX);
+            return Self::Score(MatcherConfig::SCORE_MAX);
         }
 
-        if c_len > Params::CANDIDATE_MAX_CHARS {
+        if c_len > config.candidate_max_chars {
             // Too many characters
-            return Self::Score(Params::SCORE_MIN);
+            return Self::Score(MatcherConfig::SCORE_MIN);
         }
 
         Self::Lengths(q_len, c_len)
     }
 }
 
-fn candidate_match_bonuses(candidate: &str) -> Vec<f32> {
+fn candidate_match_bonuses(candidate: &str, config: &MatcherConfig) -> Vec<f32> {
     let mut prev_char = '/';
     candidate
         .chars()
         .map(|current| {
-            let s = character_match_bonus(current, prev_char);
+            let s = character_match_bonus(current, prev_char, config);
             prev_char = current;
             s
         })
         .collect()
 }
 
-fn character_match_bonus(current: char, previous: char) -> f32 {
+fn character_match_bonus(current: char, previous: char, config: &MatcherConfig) -> f32 {
     if current.is_uppercase() && previous.is_lowercase() {
-        return Params::SCORE_MATCH_CAPITAL;
+        return config.score_match_capital;
     }
 
     match previous {
-        '/' => Params::SCORE_MATCH_SLASH,
-        '.' => Params::SCORE_MATCH_DOT,
-        _ if is_separator(previous) => Params::SCORE_MATCH_WORD,
+        '/' => config.score_match_slash,
+        '.' => config.score_match_dot,
+        _ if is_separator(previous) => config.score_match_word,
         _ => 0.0,
     }
 }
@@ -170,8 +360,16 @@ const fn is_separator(character: char) -> bool {
 mod tests {
     use super::*;
 
-    fn score(query: &str, candidate: &str) -> Score {
-        super::score(query, candidate, 0)
+    fn has_match(query: &str, candidate: &str) -> bool {
+        super::has_match(query, candidate, &MatcherConfig::default())
+    }
+
+    fn score(query: &str, candidate: &str) -> ScoreResult {
+        super::score_inner(query, candidate, 0, &MatcherConfig::default())
+    }
+
+    fn locate(query: &str, candidate: &str) -> LocateResult {
+        super::locate_inner(query, candidate, 0, &MatcherConfig::default())
     }
 
     #[test]
@@ -182,8 +380,8 @@ mod tests {
             "156aufsdn926f9=sdk/~']"
         ));
         assert!(has_match(
-            "üò®∆î¬∑¬Æx¬Ø√çƒû.…Ö∆Å√±√Æ∆π‚ô∫√†w√ë‚òÜ«àüòû¬¥∆ô¬∫√ë‚ô´",
-            "üò®∆î¬∑¬Æx¬Ø√çƒû.…Ö∆Å√±√Æ∆π‚ô∫√†w√ë‚òÜ«àüòû¬¥∆ô¬∫√ë‚ô´"
+            "héllo☆wörld→♫x.€Ω∞±≤üñ",
+            "héllo☆wörld→♫x.€Ω∞±≤üñ"
         ));
     }
 
@@ -195,8 +393,8 @@ mod tests {
         assert!(has_match("nate", "candidate"));
         assert!(has_match("56aufn92=sd/~']", "156aufsdn926f9=sdk/~']"));
         assert!(has_match(
-            "üò®∆î¬∑¬Æx¬Ø√çƒû…Ö∆Å∆π‚ô∫√†‚òÜ«à¬¥∆ô√ë‚ô´",
-            "üò®∆î¬∑¬Æx¬Ø√çƒû.…Ö∆Å√±√Æ∆π‚ô∫√†w√ë‚òÜ«àüòû¬¥∆ô¬∫√ë‚ô´"
+            "hloödx",
+            "héllo☆wörld→♫x.€Ω∞±≤üñ"
         ));
     }
 
@@ -205,14 +403,27 @@ mod tests {
         assert!(has_match("QUERY", "query"));
         assert!(has_match("query", "QUERY"));
         assert!(has_match("QuEry", "query"));
-        assert!(has_match("–ø—Ä–æ–ø–∏—Å–Ω–∞—è –±—É–∫–≤–∞", "–ü–†–û–ü–ò–°–ù–ê–Ø –ë–£–ö–í–ê"))
+        assert!(has_match("прописная буква", "ПРОПИСНАЯ БУКВА"))
+    }
+
+    #[test]
+    fn normalize_match() {
+        assert!(has_match("cafe", "café"));
+        assert!(has_match("café", "cafe"));
+
+        let config = MatcherConfig {
+            normalize: false,
+            ..MatcherConfig::default()
+        };
+        assert!(!super::has_match("cafe", "café", &config));
+        assert!(super::has_match("café", "café", &config));
     }
 
     #[test]
     fn empty_match() {
         assert!(has_match("", ""));
         assert!(has_match("", "candidate"));
-        assert!(has_match("", "üò®∆î¬∑¬Æx¬Ø√çƒû.…Ö∆Å√±√Æ∆π‚ô∫√†w√ë‚òÜ«àüòû¬¥∆ô¬∫√ë‚ô´"));
+        assert!(has_match("", "héllo☆wörld→♫x.€Ω∞±≤üñ"));
         assert!(has_match("", "–ø—Ä–æ–ø–∏—Å–Ω–∞—è –ë–£–ö–í–ê"));
         assert!(has_match("", "a"));
         assert!(has_match("", "4561"));
@@ -223,7 +434,7 @@ mod tests {
         assert!(!has_match("acb", "abc"));
         assert!(!has_match("a", ""));
         assert!(!has_match("abc", "def"));
-        assert!(!has_match("üò®∆î¬∑¬Æx¬Ø√çƒû.…Ö", "5√π¬®»º‚ôï‚ò©‚ôò‚öÅ^"));
+        assert!(!has_match("Ωñ¥€∞", "naïve café"));
         assert!(!has_match("–ø—Ä–æ–ø–∏—Å–Ω–∞—è –ë–£–ö–í–ê", "–ø—Ä–æ–ø–∏—Å–Ω–∞—è–ë–£–ö–í–ê"));
         assert!(!has_match("–ë–£–ö–í–ê –ø—Ä–æ–ø–∏—Å–Ω–∞—è", "–ø—Ä–æ–ø–∏—Å–Ω–∞—è –ë–£–ö–í–ê"));
     }
@@ -260,16 +471,16 @@ mod tests {
 
     #[test]
     fn score_exact() {
-        assert_eq!(Params::SCORE_MAX, score("query", "query").score);
+        assert_eq!(MatcherConfig::SCORE_MAX, score("query", "query").score);
         assert_eq!(
-            Params::SCORE_MAX,
+            MatcherConfig::SCORE_MAX,
             score("156aufsdn926f9=sdk/~']", "156aufsdn926f9=sdk/~']").score
         );
         assert_eq!(
-            Params::SCORE_MAX,
+            MatcherConfig::SCORE_MAX,
             score(
-                "üò®∆î¬∑¬Æx¬Ø√çƒû.…Ö∆Å√±√Æ∆π‚ô∫√†w√ë‚òÜ«àüòû¬¥∆ô¬∫√ë‚ô´",
-                "üò®∆î¬∑¬Æx¬Ø√çƒû.…Ö∆Å√±√Æ∆π‚ô∫√†w√ë‚òÜ«àüòû¬¥∆ô¬∫√ë‚ô´"
+                "héllo☆wörld→♫x.€Ω∞±≤üñ",
+                "héllo☆wörld→♫x.€Ω∞±≤üñ"
             )
             .score
         );
@@ -277,117 +488,181 @@ mod tests {
 
     #[test]
     fn score_empty() {
-        assert_eq!(Params::SCORE_MIN, score("", "").score);
-        assert_eq!(Params::SCORE_MIN, score("", "candidate").score);
+        assert_eq!(MatcherConfig::SCORE_MIN, score("", "").score);
+        assert_eq!(MatcherConfig::SCORE_MIN, score("", "candidate").score);
         assert_eq!(
-            Params::SCORE_MIN,
-            score("", "üò®∆î¬∑¬Æx¬Ø√çƒû.…Ö∆Å√±√Æ∆π‚ô∫√†w√ë‚òÜ«àüòû¬¥∆ô¬∫√ë‚ô´").score
+            MatcherConfig::SCORE_MIN,
+            score("", "héllo☆wörld→♫x.€Ω∞±≤üñ").score
         );
-        assert_eq!(Params::SCORE_MIN, score("", "–ø—Ä–æ–ø–∏—Å–Ω–∞—è –ë–£–ö–í–ê").score);
-        assert_eq!(Params::SCORE_MIN, score("", "a").score);
-        assert_eq!(Params::SCORE_MIN, score("", "4561").score);
+        assert_eq!(MatcherConfig::SCORE_MIN, score("", "–ø—Ä–æ–ø–∏—Å–Ω–∞—è –ë–£–ö–í–ê").score);
+        assert_eq!(MatcherConfig::SCORE_MIN, score("", "a").score);
+        assert_eq!(MatcherConfig::SCORE_MIN, score("", "4561").score);
     }
 
     #[test]
     fn score_gaps() {
-        assert_eq!(Params::SCORE_GAP_LEADING, score("a", "*a").score);
-        assert_eq!(Params::SCORE_GAP_LEADING * 2.0, score("a", "*ba").score);
+        let config = MatcherConfig::default();
+        assert_eq!(config.score_gap_leading, score("a", "*a").score);
+        assert_eq!(config.score_gap_leading * 2.0, score("a", "*ba").score);
         assert_eq!(
-            Params::SCORE_GAP_LEADING * 2.0 + Params::SCORE_GAP_TRAILING,
+            config.score_gap_leading * 2.0 + config.score_gap_trailing,
             score("a", "**a*").score
         );
         assert_eq!(
-            Params::SCORE_GAP_LEADING * 2.0 + Params::SCORE_GAP_TRAILING * 2.0,
+            config.score_gap_leading * 2.0 + config.score_gap_trailing * 2.0,
             score("a", "**a**").score
         );
         assert_eq!(
-            Params::SCORE_GAP_LEADING * 2.0
-                + Params::SCORE_MATCH_CONSECUTIVE
-                + Params::SCORE_GAP_TRAILING * 2.0,
-            score("aa", "**aa‚ô∫*").score
+            config.score_gap_leading * 2.0
+                + config.score_match_consecutive
+                + config.score_gap_trailing * 2.0,
+            score("aa", "**aa♺*").score
         );
         assert_eq!(
-            Params::SCORE_GAP_LEADING * 2.0
-                + Params::SCORE_GAP_INNER
-                + Params::SCORE_MATCH_WORD
-                + Params::SCORE_GAP_TRAILING * 2.0,
-            score("ab", "**a-b‚ô∫*").score
+            config.score_gap_leading * 2.0
+                + config.score_gap_inner
+                + config.score_match_word
+                + config.score_gap_trailing * 2.0,
+            score("ab", "**a-b♺*").score
         );
         assert_eq!(
-            Params::SCORE_GAP_LEADING
-                + Params::SCORE_GAP_LEADING
-                + Params::SCORE_GAP_INNER
-                + Params::SCORE_GAP_TRAILING
-                + Params::SCORE_GAP_TRAILING,
-            score("aa", "**a‚ô∫a**").score
+            config.score_gap_leading
+                + config.score_gap_leading
+                + config.score_gap_inner
+                + config.score_gap_trailing
+                + config.score_gap_trailing,
+            score("aa", "**a♺a**").score
         );
     }
 
     #[test]
     fn score_consecutive() {
+        let config = MatcherConfig::default();
         assert_eq!(
-            Params::SCORE_GAP_LEADING + Params::SCORE_MATCH_CONSECUTIVE,
+            config.score_gap_leading + config.score_match_consecutive,
             score("aa", "*aa").score
         );
         assert_eq!(
-            Params::SCORE_GAP_LEADING + Params::SCORE_MATCH_CONSECUTIVE * 2.0,
-            score("aaa", "‚ô´aaa").score
+            config.score_gap_leading + config.score_match_consecutive * 2.0,
+            score("aaa", "♫aaa").score
         );
         assert_eq!(
-            Params::SCORE_GAP_LEADING + Params::SCORE_GAP_INNER + Params::SCORE_MATCH_CONSECUTIVE,
+            config.score_gap_leading + config.score_gap_inner + config.score_match_consecutive,
             score("aaa", "*a*aa").score
         );
     }
 
     #[test]
     fn score_slash() {
+        let config = MatcherConfig::default();
         assert_eq!(
-            Params::SCORE_GAP_LEADING + Params::SCORE_MATCH_SLASH,
+            config.score_gap_leading + config.score_match_slash,
             score("a", "/a").score
         );
         assert_eq!(
-            Params::SCORE_GAP_LEADING * 2.0 + Params::SCORE_MATCH_SLASH,
+            config.score_gap_leading * 2.0 + config.score_match_slash,
             score("a", "*/a").score
         );
         assert_eq!(
-            Params::SCORE_GAP_LEADING * 2.0
-                + Params::SCORE_MATCH_SLASH
-                + Params::SCORE_MATCH_CONSECUTIVE,
+            config.score_gap_leading * 2.0
+                + config.score_match_slash
+                + config.score_match_consecutive,
             score("aa", "a/aa").score
         );
     }
 
     #[test]
     fn score_capital() {
+        let config = MatcherConfig::default();
         assert_eq!(
-            Params::SCORE_GAP_LEADING + Params::SCORE_MATCH_CAPITAL,
+            config.score_gap_leading + config.score_match_capital,
             score("a", "bA").score
         );
         assert_eq!(
-            Params::SCORE_GAP_LEADING * 2.0 + Params::SCORE_MATCH_CAPITAL,
+            config.score_gap_leading * 2.0 + config.score_match_capital,
             score("a", "baA").score
         );
         assert_eq!(
-            Params::SCORE_GAP_LEADING * 2.0
-                + Params::SCORE_MATCH_CAPITAL
-                + Params::SCORE_MATCH_CONSECUTIVE,
-            score("aa", "üòûaAa").score
+            config.score_gap_leading * 2.0
+                + config.score_match_capital
+                + config.score_match_consecutive,
+            score("aa", "😞aAa").score
         );
     }
 
     #[test]
     fn score_dot() {
+        let config = MatcherConfig::default();
         assert_eq!(
-            Params::SCORE_GAP_LEADING + Params::SCORE_MATCH_DOT,
+            config.score_gap_leading + config.score_match_dot,
             score("a", ".a").score
         );
         assert_eq!(
-            Params::SCORE_GAP_LEADING * 3.0 + Params::SCORE_MATCH_DOT,
+            config.score_gap_leading * 3.0 + config.score_match_dot,
             score("a", "*a.a").score
         );
         assert_eq!(
-            Params::SCORE_GAP_LEADING + Params::SCORE_GAP_INNER + Params::SCORE_MATCH_DOT,
-            score("a", "‚ô´a.a").score
+            config.score_gap_leading + config.score_gap_inner + config.score_match_dot,
+            score("a", "♫a.a").score
         );
     }
+
+    #[test]
+    fn locate_exact() {
+        let result = locate("query", "query");
+        assert_eq!(result.positions, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn locate_no_match() {
+        let result = locate("", "candidate");
+        assert!(result.positions.is_empty());
+    }
+
+    #[test]
+    fn locate_consecutive() {
+        let result = locate("amo", "app/models/foo");
+        assert_eq!(result.positions, vec![0, 4, 5]);
+    }
+
+    #[test]
+    fn locate_prefers_word_start() {
+        let result = locate("amor", "app/models/order");
+        assert_eq!(result.positions, vec![0, 4, 5, 12]);
+    }
+
+    #[test]
+    fn locate_matches_score() {
+        for (query, candidate) in [
+            ("amor", "app/models/order"),
+            ("gemfil", "Gemfile.lock"),
+            ("test", "testing"),
+            ("aa", "**aa♺*"),
+        ] {
+            assert_eq!(score(query, candidate).score, locate(query, candidate).score);
+        }
+    }
+
+    #[test]
+    fn smart_case_forces_case_sensitivity() {
+        let config = MatcherConfig {
+            smart_case: true,
+            ..MatcherConfig::default()
+        };
+        // Uppercase in the query makes matching case-sensitive.
+        assert!(!super::has_match("Test", "testing", &config));
+        assert!(super::has_match("Test", "Testing", &config));
+        // All-lowercase queries stay case-insensitive.
+        assert!(super::has_match("test", "Testing", &config));
+    }
+
+    #[test]
+    fn ignore_case_false_requires_exact_case() {
+        let config = MatcherConfig {
+            ignore_case: false,
+            ..MatcherConfig::default()
+        };
+        assert!(!super::has_match("test", "Testing", &config));
+        assert!(super::has_match("test", "testing", &config));
+    }
 }
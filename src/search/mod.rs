@@ -1,63 +1,65 @@
 use std::cmp::Ordering;
-use std::usize;
 
+use crate::char_bag::CharBag;
 use crate::score::{has_match, locate_inner, score_inner, LocateResult, ScoreResult};
+use crate::{MatcherConfig, SearchItem};
 
 /// Collection of scores and the candidates they apply to
 pub type ScoreResults = Vec<ScoreResult>;
 /// Collection of scores, locations, and the candidates they apply to
 pub type LocateResults = Vec<LocateResult>;
 
-pub trait SearchItem {
-    fn as_str(&self) -> &str;
-}
-
-impl SearchItem for str {
-    fn as_str(&self) -> &str {
-        self
-    }
-}
-
-impl SearchItem for String {
-    fn as_str(&self) -> &str {
-        self
-    }
-}
-
-impl<'a, S: SearchItem> SearchItem for &'a S {
-    fn as_str(&self) -> &str {
-        <_ as SearchItem>::as_str(*self)
-    }
-}
-
 /// Search among a collection of candidates using the given query, returning
 /// an ordered collection of results (highest score first)
 pub fn search_score<T: SearchItem>(query: &str, candidates: &[T]) -> ScoreResults {
-    search_internal(query, candidates, score_inner)
+    search_score_with(query, candidates, &MatcherConfig::default())
+}
+
+/// Like [`search_score`], but using the weights, limits, and case
+/// sensitivity from `config` instead of the defaults
+pub fn search_score_with<T: SearchItem>(
+    query: &str,
+    candidates: &[T],
+    config: &MatcherConfig,
+) -> ScoreResults {
+    search_internal(query, candidates, config, score_inner)
 }
 
 /// Search among a collection of candidates using the given query, returning
 /// an ordered collection of results (highest score first) with the locations
 /// of the query in each candidate
 pub fn search_locate<T: SearchItem>(query: &str, candidates: &[T]) -> LocateResults {
-    search_internal(query, candidates, locate_inner)
+    search_locate_with(query, candidates, &MatcherConfig::default())
+}
+
+/// Like [`search_locate`], but using the weights, limits, and case
+/// sensitivity from `config` instead of the defaults
+pub fn search_locate_with<T: SearchItem>(
+    query: &str,
+    candidates: &[T],
+    config: &MatcherConfig,
+) -> LocateResults {
+    search_internal(query, candidates, config, locate_inner)
 }
 
 fn search_internal<T, S>(
     query: &str,
     candidates: &[S],
-    search_fn: fn(&str, &str, usize) -> T,
+    config: &MatcherConfig,
+    search_fn: fn(&str, &str, usize, &MatcherConfig) -> T,
 ) -> Vec<T>
 where
     T: PartialOrd + Sized + Send + 'static,
     S: SearchItem,
 {
+    let query_bag = CharBag::new(query);
+
     let mut out = candidates
         .iter()
         .enumerate()
-        .filter(|(_, c)| has_match(query, c))
+        .filter(|(_, c)| c.char_bag().is_superset_of(&query_bag) && has_match(query, c, config))
         .fold(Vec::with_capacity(candidates.len()), |mut a, (i, c)| {
-            a.push(search_fn(query, c.as_str(), i));
+            a.push(search_fn(query, c.as_str(), i, config));
             a
         });
 
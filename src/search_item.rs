@@ -1,5 +1,15 @@
+use crate::char_bag::CharBag;
+
 pub trait SearchItem {
     fn as_str(&self) -> &str;
+
+    /// A cheap prefilter bitmask over this candidate's characters, used to
+    /// reject obvious non-matches before the real scan runs. Override this
+    /// when the bag can be precomputed ahead of time (see
+    /// [`crate::Bagged`]).
+    fn char_bag(&self) -> CharBag {
+        CharBag::new(self.as_str())
+    }
 }
 
 impl SearchItem for str {
@@ -24,4 +34,8 @@ impl<'a, S: SearchItem> SearchItem for &'a S {
     fn as_str(&self) -> &str {
         <_ as SearchItem>::as_str(*self)
     }
+
+    fn char_bag(&self) -> CharBag {
+        <_ as SearchItem>::char_bag(*self)
+    }
 }